@@ -0,0 +1,210 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use sqlx::{Database, Encode, Type, query::Query};
+use uuid::Uuid;
+
+use crate::types::{JsonBackend, Password, TableInfo};
+
+/// Failure binding a JSON object into a query via [`bind_json`].
+#[derive(Debug)]
+pub enum BindJsonError {
+    /// `value` passed to [`bind_json`] wasn't a JSON object.
+    NotAnObject,
+    /// A `NOT NULL` column with no default had no matching key, or an
+    /// explicit `null`, in the JSON object.
+    MissingNotNullColumn(String),
+    /// The JSON value for `column` wasn't shaped like `expected`.
+    TypeMismatch { column: String, expected: &'static str },
+    /// The JSON value for `column` was the right shape but failed to parse
+    /// into the column's affinity (e.g. a malformed UUID or URL).
+    InvalidValue { column: String, reason: String },
+}
+
+impl std::fmt::Display for BindJsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindJsonError::NotAnObject => write!(f, "expected a JSON object"),
+            BindJsonError::MissingNotNullColumn(name) => {
+                write!(f, "column `{name}` is NOT NULL but missing from the JSON object")
+            }
+            BindJsonError::TypeMismatch { column, expected } => {
+                write!(f, "column `{column}` expected a {expected} value")
+            }
+            BindJsonError::InvalidValue { column, reason } => {
+                write!(f, "column `{column}` has an invalid value: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BindJsonError {}
+
+fn as_str<'v>(column: &str, value: &'v serde_json::Value) -> Result<&'v str, BindJsonError> {
+    value.as_str().ok_or_else(|| BindJsonError::TypeMismatch {
+        column: column.to_string(),
+        expected: "string",
+    })
+}
+
+/// Binds `value` (a JSON object) into `query` as one parameter per column in
+/// `table_info`, in column order — the inverse of `SerializeRow`: where that
+/// decodes a row into JSON using each column's affinity, this encodes a JSON
+/// object into bind parameters using the same affinities. Columns missing
+/// from `value` (or explicitly `null`) bind SQL `NULL`, unless the column is
+/// `NOT NULL` with no default, which is rejected.
+pub fn bind_json<'q, DB>(
+    mut query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+    value: &'q serde_json::Value,
+    table_info: &'q [TableInfo],
+) -> Result<Query<'q, DB, <DB as Database>::Arguments<'q>>, BindJsonError>
+where
+    DB: JsonBackend,
+    bool: Encode<'q, DB> + Type<DB>,
+    i64: Encode<'q, DB> + Type<DB>,
+    f64: Encode<'q, DB> + Type<DB>,
+    &'q str: Encode<'q, DB> + Type<DB>,
+    Option<&'q str>: Encode<'q, DB> + Type<DB>,
+    String: Encode<'q, DB> + Type<DB>,
+    Vec<u8>: Encode<'q, DB> + Type<DB>,
+    Uuid: Encode<'q, DB> + Type<DB>,
+    DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    serde_json::Value: Encode<'q, DB> + Type<DB>,
+{
+    let object = value.as_object().ok_or(BindJsonError::NotAnObject)?;
+
+    for col in table_info {
+        let json_value = object.get(&col.name).filter(|v| !v.is_null());
+
+        let Some(json_value) = json_value else {
+            if col.notnull && col.dflt_value.is_empty() {
+                return Err(BindJsonError::MissingNotNullColumn(col.name.clone()));
+            }
+            query = query.bind(Option::<&str>::None);
+            continue;
+        };
+
+        let affinity = DB::affinity_of(&col.r#type);
+
+        query = match affinity.as_str() {
+            "timestamp" => {
+                let raw = as_str(&col.name, json_value)?;
+                let parsed = DateTime::parse_from_rfc3339(raw).map_err(|err| {
+                    BindJsonError::InvalidValue {
+                        column: col.name.clone(),
+                        reason: err.to_string(),
+                    }
+                })?;
+                query.bind(parsed.with_timezone(&Utc).timestamp())
+            }
+            "integer" | "bigint" => {
+                let n = json_value
+                    .as_i64()
+                    .ok_or_else(|| BindJsonError::TypeMismatch {
+                        column: col.name.clone(),
+                        expected: "integer",
+                    })?;
+                query.bind(n)
+            }
+            "real" => {
+                let n = json_value
+                    .as_f64()
+                    .ok_or_else(|| BindJsonError::TypeMismatch {
+                        column: col.name.clone(),
+                        expected: "number",
+                    })?;
+                query.bind(n)
+            }
+            "boolean" => {
+                let b = json_value
+                    .as_bool()
+                    .ok_or_else(|| BindJsonError::TypeMismatch {
+                        column: col.name.clone(),
+                        expected: "boolean",
+                    })?;
+                query.bind(b)
+            }
+            "uuid" => {
+                let raw = as_str(&col.name, json_value)?;
+                let uuid = Uuid::parse_str(raw).map_err(|err| BindJsonError::InvalidValue {
+                    column: col.name.clone(),
+                    reason: err.to_string(),
+                })?;
+                query.bind(uuid)
+            }
+            "email" => {
+                let raw = as_str(&col.name, json_value)?;
+                if raw.matches('@').count() != 1 || raw.starts_with('@') || raw.ends_with('@') {
+                    return Err(BindJsonError::InvalidValue {
+                        column: col.name.clone(),
+                        reason: "not a valid email address".to_string(),
+                    });
+                }
+                query.bind(raw.to_string())
+            }
+            "password" => {
+                let raw = as_str(&col.name, json_value)?;
+                let phc = Password::hash(raw).map_err(|err| BindJsonError::InvalidValue {
+                    column: col.name.clone(),
+                    reason: err.to_string(),
+                })?;
+                query.bind(phc)
+            }
+            "datetime" => {
+                let raw = as_str(&col.name, json_value)?;
+                let parsed = DateTime::parse_from_rfc3339(raw).map_err(|err| {
+                    BindJsonError::InvalidValue {
+                        column: col.name.clone(),
+                        reason: err.to_string(),
+                    }
+                })?;
+                query.bind(parsed.with_timezone(&Utc))
+            }
+            "date" => {
+                let raw = as_str(&col.name, json_value)?;
+                let parsed = raw
+                    .parse::<NaiveDate>()
+                    .map_err(|err| BindJsonError::InvalidValue {
+                        column: col.name.clone(),
+                        reason: err.to_string(),
+                    })?;
+                query.bind(parsed.to_string())
+            }
+            "time" => {
+                let raw = as_str(&col.name, json_value)?;
+                let parsed = raw
+                    .parse::<NaiveTime>()
+                    .map_err(|err| BindJsonError::InvalidValue {
+                        column: col.name.clone(),
+                        reason: err.to_string(),
+                    })?;
+                query.bind(parsed.to_string())
+            }
+            "url" => {
+                let raw = as_str(&col.name, json_value)?;
+                let parsed = url::Url::parse(raw).map_err(|err| BindJsonError::InvalidValue {
+                    column: col.name.clone(),
+                    reason: err.to_string(),
+                })?;
+                query.bind(parsed.to_string())
+            }
+            "json" => query.bind(json_value.clone()),
+            "blob" => {
+                // Mirrors `BlobCodec::decode_to_json`, which serializes a
+                // `Vec<u8>` as a plain JSON array of byte values.
+                let bytes: Vec<u8> = serde_json::from_value(json_value.clone()).map_err(|err| {
+                    BindJsonError::InvalidValue {
+                        column: col.name.clone(),
+                        reason: err.to_string(),
+                    }
+                })?;
+                query.bind(bytes)
+            }
+            // "text" and anything unrecognized bind as plain text.
+            _ => {
+                let raw = as_str(&col.name, json_value)?;
+                query.bind(raw.to_string())
+            }
+        };
+    }
+
+    Ok(query)
+}
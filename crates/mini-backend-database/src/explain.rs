@@ -0,0 +1,112 @@
+//! Opt-in instrumentation around [`to_json`](crate::types::to_json) fetches.
+//! Compiled in only behind the `explain` feature so it's zero-cost (not even
+//! compiled) for callers who don't ask for it.
+
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use std::time::Instant;
+
+use serde::Serialize;
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::types::{CodecRegistry, SqlxJsonExt, TableInfo};
+
+/// A single row of SQLite's `EXPLAIN QUERY PLAN` output.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct PlanNode {
+    pub id: i64,
+    pub parent: i64,
+    pub detail: String,
+}
+
+/// The query plan captured by [`to_json_explained`]: the nodes SQLite chose
+/// (index scan vs. full table scan, join order, ...) plus how long the query
+/// itself took to run.
+#[derive(Debug, Clone, Serialize)]
+pub struct QueryPlan {
+    pub nodes: Vec<PlanNode>,
+    pub duration_ms: f64,
+}
+
+fn fingerprint(sql: &str, nodes: &[PlanNode]) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    sql.hash(&mut hasher);
+    nodes.hash(&mut hasher);
+    hasher.finish()
+}
+
+static SEEN_PLANS: Mutex<Option<HashSet<u64>>> = Mutex::new(None);
+
+/// Returns `true` the first time `print` is seen for `sql`; `false` on every
+/// repeat, so a query run in a hot loop only logs its plan once.
+fn first_time_seeing(print: u64) -> bool {
+    let mut seen = SEEN_PLANS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    seen.get_or_insert_with(HashSet::new).insert(print)
+}
+
+fn log_plan(level: tracing::Level, sql: &str, plan: &QueryPlan) {
+    match level {
+        tracing::Level::ERROR => {
+            tracing::error!(sql, nodes = ?plan.nodes, duration_ms = plan.duration_ms, "query plan")
+        }
+        tracing::Level::WARN => {
+            tracing::warn!(sql, nodes = ?plan.nodes, duration_ms = plan.duration_ms, "query plan")
+        }
+        tracing::Level::INFO => {
+            tracing::info!(sql, nodes = ?plan.nodes, duration_ms = plan.duration_ms, "query plan")
+        }
+        tracing::Level::DEBUG => {
+            tracing::debug!(sql, nodes = ?plan.nodes, duration_ms = plan.duration_ms, "query plan")
+        }
+        tracing::Level::TRACE => {
+            tracing::trace!(sql, nodes = ?plan.nodes, duration_ms = plan.duration_ms, "query plan")
+        }
+    }
+}
+
+/// Runs `EXPLAIN QUERY PLAN <sql>` before `sql` itself, converts the result
+/// rows to JSON via [`to_json`](crate::types::to_json) as usual, and returns
+/// both alongside the captured [`QueryPlan`]. The plan (and how long `sql`
+/// took) is emitted through `tracing` at `level` the first time that exact
+/// plan is seen for that query text; repeats of an already-seen plan are
+/// silent, so instrumenting a query run in a hot loop doesn't flood logs.
+pub async fn to_json_explained(
+    pool: &Pool<Sqlite>,
+    sql: &str,
+    table_info: Vec<TableInfo>,
+    registry: Option<&CodecRegistry<Sqlite>>,
+    level: tracing::Level,
+) -> Result<(Vec<serde_json::Value>, QueryPlan), Box<dyn std::error::Error>> {
+    let plan_rows = sqlx::query(&format!("EXPLAIN QUERY PLAN {sql}"))
+        .fetch_all(pool)
+        .await?;
+
+    let nodes = plan_rows
+        .iter()
+        .map(|row| {
+            Ok(PlanNode {
+                id: row.try_get("id")?,
+                parent: row.try_get("parent")?,
+                detail: row.try_get("detail")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()?;
+
+    let start = Instant::now();
+    let rows = sqlx::query(sql).fetch_all(pool).await?;
+    let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let plan = QueryPlan { nodes, duration_ms };
+
+    if first_time_seeing(fingerprint(sql, &plan.nodes)) {
+        log_plan(level, sql, &plan);
+    }
+
+    let values = rows
+        .into_iter()
+        .map(|row| row.to_json(&table_info, registry))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((values, plan))
+}
@@ -1,14 +1,18 @@
+pub mod bind;
+#[cfg(feature = "explain")]
+pub mod explain;
 pub mod types;
 
 #[cfg(test)]
 pub mod test {
     #![allow(clippy::approx_constant)]
-    use chrono::Utc;
+    use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
     use serde_json::json;
     use sqlx::{Pool, Sqlite};
     use uuid::Uuid;
 
-    use crate::types::{SqlxJsonExt, TableInfo};
+    use crate::bind::bind_json;
+    use crate::types::{Password, SqlxJsonExt, TableInfo};
 
     /// Helper function to create the test table with all supported types
     async fn setup_test_table(pool: &Pool<Sqlite>) -> Result<(), sqlx::Error> {
@@ -25,6 +29,9 @@ pub mod test {
                 bool_value BOOLEAN,
                 json_data JSON_TEXT,
                 datetime_value DATETIME_TEXT,
+                date_value DATE_TEXT,
+                time_value TIME_TEXT,
+                timestamp_value TIMESTAMP_INT,
                 blob_data BLOB
             );
         "#;
@@ -43,6 +50,43 @@ pub mod test {
             .await
     }
 
+    #[test]
+    fn test_password_hash_roundtrip() {
+        let phc = Password::hash("password123").expect("hashing should succeed");
+
+        assert!(Password::verify("password123", &phc));
+        assert!(!Password::verify("wrong-password", &phc));
+        assert!(!Password::verify("password123", "not-a-valid-phc-string"));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_postgres_affinity_of() {
+        use crate::types::JsonBackend;
+
+        // information_schema reports bare type names with no SQLite-style
+        // `_TEXT`/`_INT` affinity suffix.
+        assert_eq!(<sqlx::Postgres as JsonBackend>::affinity_of("uuid"), "uuid");
+        assert_eq!(<sqlx::Postgres as JsonBackend>::affinity_of("text"), "text");
+        assert_eq!(
+            <sqlx::Postgres as JsonBackend>::affinity_of("timestamptz"),
+            "datetime"
+        );
+        assert_eq!(
+            <sqlx::Postgres as JsonBackend>::affinity_of("timestamp"),
+            "datetime"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn test_mysql_affinity_of() {
+        use crate::types::JsonBackend;
+
+        assert_eq!(<sqlx::MySql as JsonBackend>::affinity_of("UUID"), "uuid");
+        assert_eq!(<sqlx::MySql as JsonBackend>::affinity_of("VARCHAR"), "varchar");
+    }
+
     #[sqlx::test]
     async fn test_basic_type_conversion(
         pool: Pool<Sqlite>,
@@ -54,13 +98,17 @@ pub mod test {
         let test_id = Uuid::new_v4();
         let test_time = Utc::now();
         let test_json = json!({"key": "value"});
+        let test_date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let test_nt = NaiveTime::from_hms_milli_opt(13, 45, 30, 250).unwrap();
+        let test_timestamp = 1_700_000_000_i64;
 
         // Insert test data with basic types
         sqlx::query(
             r#"INSERT INTO type_test
-               (id, simple_text, nullable_text, email, password, int_value, big_int, 
-                real_value, bool_value, json_data, datetime_value, blob_data)
-               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+               (id, simple_text, nullable_text, email, password, int_value, big_int,
+                real_value, bool_value, json_data, datetime_value, date_value, time_value,
+                timestamp_value, blob_data)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
         )
         .bind(test_id)
         .bind("Simple text")
@@ -73,6 +121,9 @@ pub mod test {
         .bind(true)
         .bind(test_json.clone()) // Clone the JSON to avoid move issues
         .bind(test_time)
+        .bind(test_date)
+        .bind(test_nt)
+        .bind(test_timestamp)
         .bind(vec![1, 2, 3])
         .execute(&pool)
         .await?;
@@ -84,19 +135,27 @@ pub mod test {
         let json_row = sqlx::query("SELECT * FROM type_test")
             .fetch_one(&pool)
             .await?
-            .to_json(&table_info)?;
+            .to_json(&table_info, None)?;
 
         // Basic type assertions
         assert_eq!(json_row["id"].as_str().unwrap(), test_id.to_string());
         assert_eq!(json_row["simple_text"].as_str().unwrap(), "Simple text");
         assert!(json_row["nullable_text"].is_null());
         assert_eq!(json_row["email"].as_str().unwrap(), "test@example.com");
-        assert_eq!(json_row["password"].as_str().unwrap(), "password123");
+        assert_eq!(json_row["password"].as_str().unwrap(), "***");
         assert_eq!(json_row["int_value"].as_i64().unwrap(), 42);
         assert_eq!(json_row["big_int"].as_i64().unwrap(), 1234567890);
         assert!((json_row["real_value"].as_f64().unwrap() - 3.14).abs() < f64::EPSILON);
         assert!(json_row["bool_value"].as_bool().unwrap());
         assert_eq!(json_row["json_data"]["key"].as_str().unwrap(), "value");
+        assert_eq!(json_row["date_value"].as_str().unwrap(), "2024-03-15");
+        assert_eq!(json_row["time_value"].as_str().unwrap(), "13:45:30.250");
+        assert_eq!(
+            json_row["timestamp_value"].as_str().unwrap(),
+            DateTime::from_timestamp(test_timestamp, 0)
+                .unwrap()
+                .to_rfc3339()
+        );
 
         Ok(())
     }
@@ -129,12 +188,17 @@ pub mod test {
         let json_array = json!([1, 2, 3, 4, 5]);
         let large_blob = vec![0u8; 1024]; // 1KB blob
 
+        let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let fractional_time = NaiveTime::from_hms_micro_opt(23, 59, 59, 999_999).unwrap();
+        let pre_epoch_timestamp = -1_000_000_i64; // Pre-1970
+
         // Insert edge case values
         sqlx::query(
             r#"INSERT INTO type_test
-               (id, simple_text, nullable_text, email, password, int_value, big_int, 
-                real_value, bool_value, json_data, datetime_value, blob_data)
-               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+               (id, simple_text, nullable_text, email, password, int_value, big_int,
+                real_value, bool_value, json_data, datetime_value, date_value, time_value,
+                timestamp_value, blob_data)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
         )
         .bind(Uuid::new_v4())
         .bind(special_chars)
@@ -147,6 +211,9 @@ pub mod test {
         .bind(false)
         .bind(deep_nested_json.clone()) // Clone to avoid move
         .bind(Utc::now())
+        .bind(leap_day) // Leap day
+        .bind(fractional_time) // Fractional seconds
+        .bind(pre_epoch_timestamp) // Pre-epoch negative timestamp
         .bind(large_blob.clone())
         .execute(&pool)
         .await?;
@@ -154,9 +221,10 @@ pub mod test {
         // Insert another edge case row
         sqlx::query(
             r#"INSERT INTO type_test
-               (id, simple_text, nullable_text, email, password, int_value, big_int, 
-                real_value, bool_value, json_data, datetime_value, blob_data)
-               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+               (id, simple_text, nullable_text, email, password, int_value, big_int,
+                real_value, bool_value, json_data, datetime_value, date_value, time_value,
+                timestamp_value, blob_data)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
         )
         .bind(Uuid::new_v4())
         .bind("") // Empty string
@@ -169,6 +237,9 @@ pub mod test {
         .bind(false)
         .bind(empty_json.clone()) // Clone the empty JSON before using it
         .bind(Utc::now())
+        .bind(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()) // Epoch date
+        .bind(NaiveTime::from_hms_opt(0, 0, 0).unwrap()) // Midnight, no fraction
+        .bind(0_i64) // Epoch timestamp
         .bind(vec![0u8; 0]) // Empty blob
         .execute(&pool)
         .await?;
@@ -176,9 +247,10 @@ pub mod test {
         // Insert a row with JSON array
         sqlx::query(
             r#"INSERT INTO type_test
-               (id, simple_text, nullable_text, email, password, int_value, big_int, 
-                real_value, bool_value, json_data, datetime_value, blob_data)
-               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+               (id, simple_text, nullable_text, email, password, int_value, big_int,
+                real_value, bool_value, json_data, datetime_value, date_value, time_value,
+                timestamp_value, blob_data)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
         )
         .bind(Uuid::new_v4())
         .bind("JSON Array Test")
@@ -191,6 +263,9 @@ pub mod test {
         .bind(true)
         .bind(json_array.clone()) // Clone the JSON array before using it
         .bind(Utc::now())
+        .bind(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+        .bind(NaiveTime::from_hms_opt(12, 0, 0).unwrap())
+        .bind(1_700_000_000_i64)
         .bind(vec![9, 8, 7])
         .execute(&pool)
         .await?;
@@ -203,7 +278,7 @@ pub mod test {
             .fetch_all(&pool)
             .await?
             .into_iter()
-            .map(|row| row.to_json(&table_info))
+            .map(|row| row.to_json(&table_info, None))
             .collect::<Result<Vec<_>, _>>()?;
 
         // We should have 3 rows
@@ -213,6 +288,14 @@ pub mod test {
         let row = &json_rows[0];
         assert_eq!(row["simple_text"].as_str().unwrap(), special_chars);
         assert_eq!(row["big_int"].as_i64().unwrap(), max_int64);
+        assert_eq!(row["date_value"].as_str().unwrap(), "2024-02-29"); // Leap day
+        assert_eq!(row["time_value"].as_str().unwrap(), "23:59:59.999999"); // Fractional seconds
+        assert_eq!(
+            row["timestamp_value"].as_str().unwrap(),
+            DateTime::from_timestamp(pre_epoch_timestamp, 0)
+                .unwrap()
+                .to_rfc3339()
+        );
         assert!(row["json_data"]["level1"]["level2"]["level3"]["level4"]["array"].is_array());
         assert_eq!(
             row["json_data"]["level1"]["level2"]["level3"]["level4"]["array"][0]
@@ -240,6 +323,165 @@ pub mod test {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn test_url_type_conversion(pool: Pool<Sqlite>) -> Result<(), Box<dyn std::error::Error>> {
+        // URL_TEXT gets its own table so invalid-URL rows don't have to carry
+        // dummy values for every other column in type_test.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS url_test (
+                id INTEGER PRIMARY KEY,
+                url_value URL_TEXT,
+                nullable_url URL_TEXT NULL
+            );
+        "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query("INSERT INTO url_test (id, url_value, nullable_url) VALUES (?, ?, ?)")
+            .bind(1)
+            .bind("https://example.com/path?query=1")
+            .bind(None::<String>)
+            .execute(&pool)
+            .await?;
+
+        sqlx::query("INSERT INTO url_test (id, url_value, nullable_url) VALUES (?, ?, ?)")
+            .bind(2)
+            .bind("just/a/relative/path") // Schemeless/relative, not a valid absolute URL
+            .bind(None::<String>)
+            .execute(&pool)
+            .await?;
+
+        let table_info = get_table_info(&pool, "url_test").await?;
+
+        let valid_row = sqlx::query("SELECT * FROM url_test WHERE id = 1")
+            .fetch_one(&pool)
+            .await?
+            .to_json(&table_info, None)?;
+
+        assert_eq!(
+            valid_row["url_value"].as_str().unwrap(),
+            "https://example.com/path?query=1"
+        );
+        assert!(valid_row["nullable_url"].is_null());
+
+        let invalid_row_result = sqlx::query("SELECT * FROM url_test WHERE id = 2")
+            .fetch_one(&pool)
+            .await?
+            .to_json(&table_info, None);
+
+        assert!(
+            invalid_row_result.is_err(),
+            "schemeless/relative strings should fail to parse as a URL"
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_bind_json_roundtrip(pool: Pool<Sqlite>) -> Result<(), Box<dyn std::error::Error>> {
+        // A table of its own (rather than the shared type_test table) keeps
+        // this test focused on bind_json's own column set.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS bind_test (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                email EMAIL_TEXT,
+                password PASSWORD_TEXT,
+                active BOOLEAN,
+                score REAL,
+                avatar BLOB
+            );
+        "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        let table_info = get_table_info(&pool, "bind_test").await?;
+
+        // BLOB columns round-trip as a plain JSON array of byte values,
+        // mirroring `BlobCodec::decode_to_json` on the read side, not base64.
+        let payload = json!({
+            "id": 1,
+            "name": "Ada Lovelace",
+            "email": "ada@example.com",
+            "password": "hunter2",
+            "active": true,
+            "score": 98.6,
+            "avatar": [0xDE, 0xAD, 0xBE, 0xEF],
+        });
+
+        let query = sqlx::query(
+            "INSERT INTO bind_test (id, name, email, password, active, score, avatar) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        );
+        bind_json(query, &payload, &table_info)?.execute(&pool).await?;
+
+        let row = sqlx::query("SELECT * FROM bind_test WHERE id = 1")
+            .fetch_one(&pool)
+            .await?
+            .to_json(&table_info, None)?;
+
+        assert_eq!(row["name"].as_str().unwrap(), "Ada Lovelace");
+        assert_eq!(row["email"].as_str().unwrap(), "ada@example.com");
+        assert_eq!(row["password"].as_str().unwrap(), "***"); // Never the plaintext
+        assert!(row["active"].as_bool().unwrap());
+        assert!((row["score"].as_f64().unwrap() - 98.6).abs() < f64::EPSILON);
+        assert_eq!(row["avatar"], json!([0xDE, 0xAD, 0xBE, 0xEF]));
+
+        let stored_hash: String = sqlx::query_scalar("SELECT password FROM bind_test WHERE id = 1")
+            .fetch_one(&pool)
+            .await?;
+        assert_ne!(stored_hash, "hunter2"); // Stored as a PHC hash, not plaintext
+        assert!(Password::verify("hunter2", &stored_hash));
+
+        // A NOT NULL column missing from the JSON object is rejected up front.
+        let missing_name = json!({ "id": 2 });
+        let query = sqlx::query("INSERT INTO bind_test (id, name) VALUES (?, ?)");
+        assert!(bind_json(query, &missing_name, &table_info).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "explain")]
+    #[sqlx::test]
+    async fn test_to_json_explained(pool: Pool<Sqlite>) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::explain::to_json_explained;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS explain_test (
+                id INTEGER PRIMARY KEY,
+                name TEXT
+            );
+        "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query("INSERT INTO explain_test (id, name) VALUES (1, 'Ada')")
+            .execute(&pool)
+            .await?;
+
+        let table_info = get_table_info(&pool, "explain_test").await?;
+
+        let (rows, plan) = to_json_explained(
+            &pool,
+            "SELECT * FROM explain_test WHERE id = 1",
+            table_info,
+            None,
+            tracing::Level::DEBUG,
+        )
+        .await?;
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0]["name"].as_str().unwrap(), "Ada");
+        assert!(!plan.nodes.is_empty(), "EXPLAIN QUERY PLAN should return at least one node");
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn test_multiple_rows_and_aggregation(
         pool: Pool<Sqlite>,
@@ -275,7 +517,7 @@ pub mod test {
             .fetch_all(&pool)
             .await?
             .into_iter()
-            .map(|row| row.to_json(&table_info))
+            .map(|row| row.to_json(&table_info, None))
             .collect::<Result<Vec<_>, _>>()?;
 
         assert_eq!(all_rows.len(), 100, "Should have 100 rows");
@@ -285,7 +527,7 @@ pub mod test {
             .fetch_all(&pool)
             .await?
             .into_iter()
-            .map(|row| row.to_json(&table_info))
+            .map(|row| row.to_json(&table_info, None))
             .collect::<Result<Vec<_>, _>>()?;
 
         assert_eq!(
@@ -329,7 +571,7 @@ pub mod test {
             },
         ];
 
-        let agg_json = aggregate_row.to_json(&agg_table_info)?;
+        let agg_json = aggregate_row.to_json(&agg_table_info, None)?;
 
         assert_eq!(agg_json["count"].as_i64().unwrap(), 100);
         assert_eq!(agg_json["sum"].as_i64().unwrap(), 50500); // Sum of 10*i for i from 1 to 100
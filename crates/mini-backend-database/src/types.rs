@@ -1,24 +1,75 @@
-use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use argon2::{
+    Argon2, PasswordHash, PasswordHasher, PasswordVerifier,
+    password_hash::{Error as PasswordHashError, SaltString},
+};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use rand_core::OsRng;
 use serde::{Serialize, ser::SerializeMap};
-use sqlx::{Database, Decode, Row, prelude::FromRow};
+use sqlx::{Database, Decode, Row, error::BoxDynError, prelude::FromRow};
+use url::Url;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize)]
+/// Wraps a `PASSWORD_TEXT` value. The inner string is always the Argon2 PHC
+/// hash, never plaintext, and is never emitted as-is when serialized — see
+/// the manual `Serialize` impl below.
+#[derive(Debug, Clone)]
 pub struct Password(String);
 
+impl Password {
+    /// Hashes `plaintext` into a PHC string using Argon2 with a freshly
+    /// generated salt. The result is what should be stored in a
+    /// `PASSWORD_TEXT` column.
+    pub fn hash(plaintext: &str) -> Result<String, PasswordHashError> {
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = Argon2::default().hash_password(plaintext.as_bytes(), &salt)?;
+        Ok(hash.to_string())
+    }
+
+    /// Verifies `plaintext` against a PHC hash string previously produced by
+    /// [`Password::hash`]. Returns `false` for both a wrong password and a
+    /// malformed hash.
+    pub fn verify(plaintext: &str, phc: &str) -> bool {
+        let Ok(parsed) = PasswordHash::new(phc) else {
+            return false;
+        };
+        Argon2::default()
+            .verify_password(plaintext.as_bytes(), &parsed)
+            .is_ok()
+    }
+}
+
+/// Never serializes the stored hash; emits a constant placeholder so
+/// `PASSWORD_TEXT` values can't leak into JSON responses.
+impl Serialize for Password {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str("***")
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Email(String);
 
-#[derive(FromRow, Debug)]
+#[derive(FromRow, Debug, Clone)]
 pub struct TableInfo {
-    cid: i64,
-    name: String,
-    r#type: String,
-    notnull: bool,
-    dflt_value: String,
-    pk: bool,
+    pub(crate) cid: i64,
+    pub(crate) name: String,
+    pub(crate) r#type: String,
+    pub(crate) notnull: bool,
+    pub(crate) dflt_value: String,
+    pub(crate) pk: bool,
 }
 
+/// The logical types this crate knows how to decode out of the box. Kept
+/// around as a closed enum for callers who want an owned, match-able value;
+/// [`CodecRegistry`] is the open-ended counterpart used when serializing a
+/// row, since a `match` over this enum can't be extended from outside the
+/// crate.
 #[derive(Debug, Clone, Serialize)]
 pub enum DatabaseTypes {
     Email(Email),
@@ -29,18 +80,424 @@ pub enum DatabaseTypes {
     Boolean(bool),
     Blob(Vec<u8>),
     Json(serde_json::Value),
+    /// `url::Url` only implements `Serialize` behind that crate's own
+    /// `serde` feature, which isn't enabled here — serialize it as its
+    /// normalized string form instead.
+    Url(#[serde(serialize_with = "serialize_url")] Url),
+}
+
+fn serialize_url<S>(url: &Url, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(url.as_str())
+}
+
+/// Normalizes a backend's raw catalog type name into the affinity string
+/// [`ColumnCodec`]s are keyed by, so the same `CodecRegistry`/`SerializeRow`
+/// machinery can run against any `sqlx` backend rather than being wired
+/// directly to SQLite. SQLite reports column types straight from `PRAGMA
+/// table_info` (e.g. `UUID_TEXT`); other backends describe columns through
+/// their own catalogs (Postgres' `information_schema.columns`, MySQL's
+/// `COLUMN_TYPE`), so normalization is backend-specific even though the
+/// codec lookup afterwards isn't.
+pub trait JsonBackend: Database {
+    /// Normalizes a raw catalog type name (as stored in [`TableInfo::r#type`])
+    /// into the underscore-prefixed affinity string used to look up a
+    /// [`ColumnCodec`], e.g. both SQLite's `UUID_TEXT` and a bare `uuid`
+    /// normalize to `"uuid"`.
+    fn affinity_of(raw_type: &str) -> String {
+        raw_type
+            .to_lowercase()
+            .split('_')
+            .next()
+            .unwrap_or("")
+            .to_string()
+    }
+}
+
+impl JsonBackend for sqlx::Sqlite {}
+
+#[cfg(feature = "postgres")]
+impl JsonBackend for sqlx::Postgres {
+    fn affinity_of(raw_type: &str) -> String {
+        // information_schema reports bare type names (`uuid`, `timestamptz`,
+        // ...) with none of SQLite's `_TEXT`/`_INT` affinity suffixes.
+        match raw_type.to_lowercase().as_str() {
+            "timestamptz" | "timestamp" => "datetime".to_string(),
+            other => other.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl JsonBackend for sqlx::MySql {
+    fn affinity_of(raw_type: &str) -> String {
+        raw_type.to_lowercase()
+    }
+}
+
+/// Decodes a single logical column affinity (the part of a SQLite column
+/// type before its first underscore, e.g. `uuid` in `UUID_TEXT`) into JSON, for a
+/// specific `sqlx` backend `DB`. Mirrors the split between rusqlite's
+/// `FromSql`/`ToSql`: a `CodecRegistry` is just a map from affinity name to
+/// a boxed `ColumnCodec`, so registering a new affinity (money, geo, a
+/// custom enum, ...) doesn't require forking this crate or touching
+/// `SerializeRow`.
+pub trait ColumnCodec<DB: Database>: Send + Sync {
+    /// The affinity this codec handles, e.g. `"uuid"` for a `UUID_TEXT` column.
+    fn affinity(&self) -> &str;
+
+    /// Decodes the raw value into its JSON representation.
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError>;
+}
+
+struct TextCodec;
+
+impl<DB: Database> ColumnCodec<DB> for TextCodec
+where
+    for<'r> &'r str: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "text"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let value = <&str>::decode(raw_value)?;
+        Ok(serde_json::Value::String(value.to_string()))
+    }
+}
+
+struct IntegerCodec;
+
+impl<DB: Database> ColumnCodec<DB> for IntegerCodec
+where
+    for<'r> i32: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "integer"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let value = i32::decode(raw_value)?;
+        Ok(serde_json::Value::from(value))
+    }
+}
+
+struct BigintCodec;
+
+impl<DB: Database> ColumnCodec<DB> for BigintCodec
+where
+    for<'r> i64: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "bigint"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let value = i64::decode(raw_value)?;
+        Ok(serde_json::Value::from(value))
+    }
+}
+
+struct RealCodec;
+
+impl<DB: Database> ColumnCodec<DB> for RealCodec
+where
+    for<'r> f64: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "real"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let value = f64::decode(raw_value)?;
+        Ok(serde_json::Value::from(value))
+    }
+}
+
+struct BooleanCodec;
+
+impl<DB: Database> ColumnCodec<DB> for BooleanCodec
+where
+    for<'r> bool: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "boolean"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let value = bool::decode(raw_value)?;
+        Ok(serde_json::Value::from(value))
+    }
+}
+
+struct BlobCodec;
+
+impl<DB: Database> ColumnCodec<DB> for BlobCodec
+where
+    for<'r> Vec<u8>: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "blob"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let value = Vec::<u8>::decode(raw_value)?;
+        Ok(serde_json::to_value(value)?)
+    }
+}
+
+struct UuidCodec;
+
+impl<DB: Database> ColumnCodec<DB> for UuidCodec
+where
+    for<'r> Uuid: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "uuid"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let value = Uuid::decode(raw_value)?;
+        Ok(serde_json::to_value(value)?)
+    }
+}
+
+struct DatetimeCodec;
+
+impl<DB: Database> ColumnCodec<DB> for DatetimeCodec
+where
+    for<'r> DateTime<Utc>: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "datetime"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let value = DateTime::<Utc>::decode(raw_value)?;
+        Ok(serde_json::to_value(value)?)
+    }
+}
+
+struct PasswordCodec;
+
+impl<DB: Database> ColumnCodec<DB> for PasswordCodec
+where
+    for<'r> &'r str: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "password"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        // Decoded only to validate the stored value; the hash itself must
+        // never reach the output JSON.
+        <&str>::decode(raw_value)?;
+        Ok(serde_json::Value::String("***".to_string()))
+    }
+}
+
+struct EmailCodec;
+
+impl<DB: Database> ColumnCodec<DB> for EmailCodec
+where
+    for<'r> &'r str: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "email"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let value = <&str>::decode(raw_value)?;
+        Ok(serde_json::Value::String(value.to_string()))
+    }
+}
+
+struct JsonCodec;
+
+impl<DB: Database> ColumnCodec<DB> for JsonCodec
+where
+    for<'r> serde_json::Value: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "json"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        Ok(serde_json::Value::decode(raw_value)?)
+    }
+}
+
+/// `DATE_TEXT`: an ISO-8601 `YYYY-MM-DD` calendar date with no time-of-day.
+struct DateCodec;
+
+impl<DB: Database> ColumnCodec<DB> for DateCodec
+where
+    for<'r> NaiveDate: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "date"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let value = NaiveDate::decode(raw_value)?;
+        Ok(serde_json::to_value(value)?)
+    }
+}
+
+/// `TIME_TEXT`: a wall-clock `HH:MM:SS[.fff]` time with no date.
+struct TimeCodec;
+
+impl<DB: Database> ColumnCodec<DB> for TimeCodec
+where
+    for<'r> NaiveTime: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "time"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let value = NaiveTime::decode(raw_value)?;
+        Ok(serde_json::to_value(value)?)
+    }
 }
 
-pub struct SerializeRow<R: Row>(pub (Vec<TableInfo>, R));
+/// `TIMESTAMP_INT`: Unix-epoch seconds stored as an `INTEGER`, serialized as
+/// an RFC-3339 string.
+struct TimestampIntCodec;
+
+impl<DB: Database> ColumnCodec<DB> for TimestampIntCodec
+where
+    for<'r> i64: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "timestamp"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let epoch_seconds = i64::decode(raw_value)?;
+        let value = DateTime::from_timestamp(epoch_seconds, 0)
+            .ok_or("timestamp out of range for DateTime<Utc>")?;
+        Ok(serde_json::Value::String(value.to_rfc3339()))
+    }
+}
+
+/// `URL_TEXT`: validated via [`Url::parse`] on the way out, serialized as
+/// its normalized string form. A stored value that fails to parse surfaces
+/// as a decode error rather than being silently passed through.
+struct UrlCodec;
+
+impl<DB: Database> ColumnCodec<DB> for UrlCodec
+where
+    for<'r> &'r str: Decode<'r, DB>,
+{
+    fn affinity(&self) -> &str {
+        "url"
+    }
+
+    fn decode_to_json<'r>(&self, raw_value: DB::ValueRef<'r>) -> Result<serde_json::Value, BoxDynError> {
+        let raw = <&str>::decode(raw_value)?;
+        let value = Url::parse(raw)?;
+        Ok(serde_json::Value::String(value.to_string()))
+    }
+}
+
+/// Maps affinity strings (the normalized half of a column type like
+/// `UUID_TEXT`, produced by [`JsonBackend::affinity_of`]) to the
+/// [`ColumnCodec`] that decodes it for backend `DB`. [`CodecRegistry::default`]
+/// is pre-populated with every affinity `SerializeRow` understood before this
+/// registry existed; register additional codecs to extend `DatabaseTypes`
+/// without forking the crate. Affinities with no registered codec fall back
+/// to raw `BLOB` bytes, matching the old hardcoded match's default arm.
+pub struct CodecRegistry<DB: Database> {
+    codecs: HashMap<String, Arc<dyn ColumnCodec<DB>>>,
+}
+
+impl<DB: Database> Clone for CodecRegistry<DB> {
+    /// Cheap: codecs are stored behind `Arc`, so cloning just bumps refcounts.
+    fn clone(&self) -> Self {
+        Self {
+            codecs: self.codecs.clone(),
+        }
+    }
+}
+
+impl<DB: Database> CodecRegistry<DB> {
+    /// An empty registry with no codecs registered, not even the built-ins.
+    pub fn empty() -> Self {
+        Self {
+            codecs: HashMap::new(),
+        }
+    }
+
+    /// Registers `codec`, keyed by its own [`ColumnCodec::affinity`].
+    /// Replaces any codec previously registered for the same affinity.
+    pub fn register(&mut self, codec: impl ColumnCodec<DB> + 'static) -> &mut Self {
+        self.codecs.insert(codec.affinity().to_string(), Arc::new(codec));
+        self
+    }
+
+    /// Registers an additional affinity name that resolves to the same
+    /// codec already registered under `canonical`, e.g. SQLite's `int4`/`int8`
+    /// aliases for `integer`/`bigint`. A no-op if `canonical` isn't registered.
+    pub fn alias(&mut self, alias: &str, canonical: &str) -> &mut Self {
+        if let Some(codec) = self.codecs.get(canonical).cloned() {
+            self.codecs.insert(alias.to_string(), codec);
+        }
+        self
+    }
+
+    fn get(&self, affinity: &str) -> Option<&dyn ColumnCodec<DB>> {
+        self.codecs.get(affinity).map(|codec| codec.as_ref())
+    }
+}
+
+impl<DB: Database> Default for CodecRegistry<DB>
+where
+    for<'r> &'r str: Decode<'r, DB>,
+    for<'r> i32: Decode<'r, DB>,
+    for<'r> i64: Decode<'r, DB>,
+    for<'r> f64: Decode<'r, DB>,
+    for<'r> bool: Decode<'r, DB>,
+    for<'r> Vec<u8>: Decode<'r, DB>,
+    for<'r> Uuid: Decode<'r, DB>,
+    for<'r> DateTime<Utc>: Decode<'r, DB>,
+    for<'r> NaiveDate: Decode<'r, DB>,
+    for<'r> NaiveTime: Decode<'r, DB>,
+    for<'r> serde_json::Value: Decode<'r, DB>,
+{
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry
+            .register(TextCodec)
+            .register(IntegerCodec)
+            .register(BigintCodec)
+            .register(RealCodec)
+            .register(BooleanCodec)
+            .register(BlobCodec)
+            .register(UuidCodec)
+            .register(DatetimeCodec)
+            .register(PasswordCodec)
+            .register(EmailCodec)
+            .register(JsonCodec)
+            .register(DateCodec)
+            .register(TimeCodec)
+            .register(TimestampIntCodec)
+            .register(UrlCodec);
+        registry.alias("int4", "integer");
+        registry.alias("int8", "bigint");
+        registry
+    }
+}
+
+pub struct SerializeRow<R: Row>(pub (Vec<TableInfo>, R, CodecRegistry<R::Database>));
 
 impl<'r, R: Row> Serialize for &'r SerializeRow<R>
 where
-    R::Database: sqlx::Database<ValueRef<'r> = sqlx::sqlite::SqliteValueRef<'r>>,
+    R::Database: JsonBackend,
     usize: sqlx::ColumnIndex<R>,
-    &'r str: sqlx::Decode<'r, <R as Row>::Database>,
-    f64: sqlx::Decode<'r, <R as Row>::Database>,
-    i64: sqlx::Decode<'r, <R as Row>::Database>,
-    bool: sqlx::Decode<'r, <R as Row>::Database>,
+    Vec<u8>: sqlx::Decode<'r, <R as Row>::Database>,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -48,7 +505,7 @@ where
     {
         use sqlx::{Column, TypeInfo, ValueRef};
 
-        let (table_info, row) = &self.0;
+        let (table_info, row, registry) = &self.0;
 
         let columns = row.columns();
 
@@ -63,52 +520,17 @@ where
 
             match row.try_get_raw(col.ordinal()) {
                 Ok(raw_value) if !raw_value.is_null() => {
-                    match col_def.r#type.to_lowercase().as_str() {
-                        // sqlite primitive types
-                        "text" => SerializeRow::map_serialize::<_, sqlx::Sqlite, &str>(
-                            &mut map, key, raw_value,
-                        ),
-                        "integer" | "int4" => SerializeRow::map_serialize::<_, sqlx::Sqlite, i32>(
-                            &mut map, key, raw_value,
-                        ),
-                        "bigint" | "int8" => SerializeRow::map_serialize::<_, sqlx::Sqlite, i64>(
-                            &mut map, key, raw_value,
-                        ),
-                        "real" => SerializeRow::map_serialize::<_, sqlx::Sqlite, f64>(
-                            &mut map, key, raw_value,
-                        ),
-                        "boolean" => SerializeRow::map_serialize::<_, sqlx::Sqlite, bool>(
+                    let affinity = <R::Database as JsonBackend>::affinity_of(&col_def.r#type);
+                    match registry.get(&affinity) {
+                        Some(codec) => {
+                            let value = codec
+                                .decode_to_json(raw_value)
+                                .map_err(serde::ser::Error::custom)?;
+                            map.serialize_entry(key, &value)
+                        }
+                        None => SerializeRow::<R>::map_serialize::<_, R::Database, Vec<u8>>(
                             &mut map, key, raw_value,
                         ),
-                        col_type => {
-                            let mut split = col_type.split('_');
-                            let main_type = split.next().unwrap_or("");
-                            let fallback_type = split.next().unwrap_or("");
-                            match main_type {
-                                "uuid" => SerializeRow::map_serialize::<_, sqlx::Sqlite, Uuid>(
-                                    &mut map, key, raw_value,
-                                ),
-                                "datetime" => {
-                                    SerializeRow::map_serialize::<_, sqlx::Sqlite, DateTime<Utc>>(
-                                        &mut map, key, raw_value,
-                                    )
-                                }
-                                "password" => SerializeRow::map_serialize::<_, sqlx::Sqlite, &str>(
-                                    &mut map, key, raw_value,
-                                ),
-                                "email" => SerializeRow::map_serialize::<_, sqlx::Sqlite, &str>(
-                                    &mut map, key, raw_value,
-                                ),
-                                "json" => {
-                                    SerializeRow::map_serialize::<_, sqlx::Sqlite, serde_json::Value>(
-                                        &mut map, key, raw_value,
-                                    )
-                                }
-                                _ => SerializeRow::map_serialize::<_, sqlx::Sqlite, Vec<u8>>(
-                                    &mut map, key, raw_value,
-                                ),
-                            }
-                        }
                     }
                 }
                 _ => map.serialize_entry(key, &()),
@@ -119,16 +541,8 @@ where
     }
 }
 
-impl<'r, R: Row> SerializeRow<R>
-where
-    R::Database: sqlx::Database<ValueRef<'r> = sqlx::sqlite::SqliteValueRef<'r>>,
-    usize: sqlx::ColumnIndex<R>,
-    &'r str: sqlx::Decode<'r, <R as Row>::Database>,
-    f64: sqlx::Decode<'r, <R as Row>::Database>,
-    i64: sqlx::Decode<'r, <R as Row>::Database>,
-    bool: sqlx::Decode<'r, <R as Row>::Database>,
-{
-    fn map_serialize<M: SerializeMap, DB: Database, T: Decode<'r, DB> + Serialize>(
+impl<R: Row> SerializeRow<R> {
+    fn map_serialize<'r, M: SerializeMap, DB: Database, T: Decode<'r, DB> + Serialize>(
         map: &mut M,
         key: &str,
         raw_value: <DB as Database>::ValueRef<'r>,
@@ -141,46 +555,53 @@ where
 pub trait SqlxJsonExt<'r, R>
 where
     R: Row,
-    R::Database: sqlx::Database<ValueRef<'r> = sqlx::sqlite::SqliteValueRef<'r>>,
-    for<'a> &'a SerializeRow<R>: Serialize,
+    R::Database: JsonBackend,
 {
+    /// Converts `self` into a JSON object keyed by column name, using
+    /// `registry` to decode each column's affinity. Pass `None` to fall back
+    /// to [`CodecRegistry::default`]'s built-ins.
     fn to_json(
         self,
-        table_info: Vec<TableInfo>,
+        table_info: &[TableInfo],
+        registry: Option<&CodecRegistry<R::Database>>,
     ) -> Result<serde_json::Value, Box<dyn std::error::Error>>
     where
-        R::Database: sqlx::Database<ValueRef<'r> = sqlx::sqlite::SqliteValueRef<'r>>,
-        for<'a> &'a SerializeRow<R>: Serialize;
+        for<'a> &'a SerializeRow<R>: Serialize,
+        CodecRegistry<R::Database>: Default;
 }
 
 impl<'r, R: Row> SqlxJsonExt<'r, R> for R
 where
-    R::Database: sqlx::Database<ValueRef<'r> = sqlx::sqlite::SqliteValueRef<'r>>,
-    for<'a> &'a SerializeRow<R>: Serialize,
+    R::Database: JsonBackend,
 {
     fn to_json(
         self,
-        table_info: Vec<TableInfo>,
+        table_info: &[TableInfo],
+        registry: Option<&CodecRegistry<R::Database>>,
     ) -> Result<serde_json::Value, Box<dyn std::error::Error>>
     where
-        R::Database: sqlx::Database<ValueRef<'r> = sqlx::sqlite::SqliteValueRef<'r>>,
         for<'a> &'a SerializeRow<R>: Serialize,
+        CodecRegistry<R::Database>: Default,
     {
-        let serialize_row = SerializeRow((table_info, self));
+        let registry = registry.cloned().unwrap_or_default();
+        let serialize_row = SerializeRow((table_info.to_vec(), self, registry));
         let val = serde_json::to_value(&serialize_row)?;
         Ok(val)
     }
 }
 
-pub fn to_json<'r, R: Row>(
+pub fn to_json<R: Row>(
     row: R,
-    table_info: Vec<TableInfo>,
+    table_info: &[TableInfo],
+    registry: Option<&CodecRegistry<R::Database>>,
 ) -> Result<serde_json::Value, Box<dyn std::error::Error>>
 where
-    R::Database: sqlx::Database<ValueRef<'r> = sqlx::sqlite::SqliteValueRef<'r>>,
+    R::Database: JsonBackend,
     for<'a> &'a SerializeRow<R>: Serialize,
+    CodecRegistry<R::Database>: Default,
 {
-    let serialize_row = SerializeRow((table_info, row));
+    let registry = registry.cloned().unwrap_or_default();
+    let serialize_row = SerializeRow((table_info.to_vec(), row, registry));
     let val = serde_json::to_value(&serialize_row)?;
     Ok(val)
 }